@@ -1,42 +1,397 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+use std::fs;
+use std::io::Cursor;
+use std::path::PathBuf;
 use std::process::Command;
+use std::thread;
 
+use serde::{Deserialize, Serialize};
 use tauri::Manager;
 
+// Bundled notification sound played before a scheduled action runs.
+const ALERT_SOUND: &[u8] = include_bytes!("../sounds/alert.wav");
+
+// The power action requested by the front-end. Variants are deserialized from the
+// matching PascalCase string sent through `invoke`.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum PowerAction {
+    Shutdown,
+    Restart,
+    Sleep,
+    Hibernate,
+    LogOff,
+}
+
+// Persisted user settings. Serialized to a JSON file under the OS config dir and
+// reloaded on launch.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Settings {
+    pub default_action: PowerAction,
+    pub warn_seconds: u32,
+    pub force_shutdown_count: u8,
+    pub presets: Vec<i64>,
+    #[serde(default)]
+    pub power_off_on_complete: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            default_action: PowerAction::Shutdown,
+            warn_seconds: 60,
+            force_shutdown_count: 3,
+            presets: vec![10, 20, 30],
+            power_off_on_complete: false,
+        }
+    }
+}
+
+// `<config dir>/rust-in-peace/settings.json`, falling back to the current dir if the
+// OS config dir cannot be resolved.
+fn settings_path() -> PathBuf {
+    let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+    path.push("rust-in-peace");
+    path.push("settings.json");
+    path
+}
+
+#[tauri::command]
+fn load_settings() -> Settings {
+    fs::read_to_string(settings_path())
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+#[tauri::command]
+fn save_settings(settings: Settings) {
+    let path = settings_path();
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    if let Ok(contents) = serde_json::to_string_pretty(&settings) {
+        let _ = fs::write(path, contents);
+    }
+}
+
 // Learn more about Tauri commands at https://tauri.app/v1/guides/features/command
 #[tauri::command]
-fn shutdown_pc() {
+fn power_action(action: PowerAction) {
+    match action {
+        PowerAction::Shutdown => run_power_command(
+            &["/s", "/t", "0"],
+            "tell application \"System Events\" to shut down",
+            &["-h", "now"],
+        ),
+        PowerAction::Restart => run_restart(),
+        PowerAction::LogOff => run_log_off(),
+        PowerAction::Sleep => run_sleep(),
+        PowerAction::Hibernate => run_hibernate(),
+    }
+}
+
+// Fire the right per-OS `shutdown`/`osascript` invocation for the actions that map
+// cleanly onto those tools.
+fn run_power_command(windows_args: &[&str], macos_event: &str, linux_args: &[&str]) {
     #[cfg(target_os = "windows")]
     {
+        let _ = macos_event;
+        let _ = linux_args;
         Command::new("shutdown")
-            .args(&["/s", "/t", "0"])
+            .args(windows_args)
             .spawn()
-            .expect("Failed to shutdown the system");
+            .expect("Failed to run the power action");
     }
 
     #[cfg(target_os = "macos")]
     {
+        let _ = windows_args;
+        let _ = linux_args;
         Command::new("osascript")
             .arg("-e")
-            .arg("tell application \"System Events\" to shut down")
+            .arg(macos_event)
             .spawn()
-            .expect("Failed to shutdown the system");
+            .expect("Failed to run the power action");
     }
 
     #[cfg(target_os = "linux")]
+    {
+        let _ = windows_args;
+        let _ = macos_event;
+        Command::new("shutdown")
+            .args(linux_args)
+            .spawn()
+            .expect("Failed to run the power action");
+    }
+}
+
+fn run_restart() {
+    #[cfg(target_os = "windows")]
     {
         Command::new("shutdown")
-            .args(&["-h", "now"])
+            .args(&["/r", "/t", "0"])
             .spawn()
-            .expect("Failed to shutdown the system");
+            .expect("Failed to restart the system");
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        Command::new("osascript")
+            .arg("-e")
+            .arg("tell application \"System Events\" to restart")
+            .spawn()
+            .expect("Failed to restart the system");
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        Command::new("systemctl")
+            .arg("reboot")
+            .spawn()
+            .or_else(|_| Command::new("shutdown").args(&["-r", "now"]).spawn())
+            .expect("Failed to restart the system");
+    }
+}
+
+// End the current user session rather than powering the machine off. On Linux this
+// means a real session-logout path, never `shutdown`.
+fn run_log_off() {
+    #[cfg(target_os = "windows")]
+    {
+        Command::new("shutdown")
+            .arg("/l")
+            .spawn()
+            .expect("Failed to log off");
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        Command::new("osascript")
+            .arg("-e")
+            .arg("tell application \"System Events\" to log out")
+            .spawn()
+            .expect("Failed to log off");
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let user = std::env::var("USER").unwrap_or_default();
+        Command::new("loginctl")
+            .args(&["terminate-user", &user])
+            .spawn()
+            .or_else(|_| Command::new("gnome-session-quit").arg("--logout").spawn())
+            .expect("Failed to log off");
+    }
+}
+
+fn run_sleep() {
+    #[cfg(target_os = "windows")]
+    {
+        Command::new("rundll32.exe")
+            .args(&["powrprof.dll,SetSuspendState", "0,1,0"])
+            .spawn()
+            .expect("Failed to sleep the system");
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        Command::new("pmset")
+            .arg("sleepnow")
+            .spawn()
+            .expect("Failed to sleep the system");
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        Command::new("systemctl")
+            .arg("suspend")
+            .spawn()
+            .or_else(|_| Command::new("shutdown").args(&["-h", "now"]).spawn())
+            .expect("Failed to sleep the system");
+    }
+}
+
+fn run_hibernate() {
+    #[cfg(target_os = "windows")]
+    {
+        Command::new("shutdown")
+            .arg("/h")
+            .spawn()
+            .expect("Failed to hibernate the system");
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        Command::new("pmset")
+            .arg("sleepnow")
+            .spawn()
+            .expect("Failed to hibernate the system");
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        Command::new("systemctl")
+            .arg("hibernate")
+            .spawn()
+            .or_else(|_| Command::new("shutdown").args(&["-h", "now"]).spawn())
+            .expect("Failed to hibernate the system");
+    }
+}
+
+// Play the bundled notification sound on a spawned thread so the UI never blocks.
+// Silently does nothing when no output device is available.
+#[tauri::command]
+fn play_alert() {
+    thread::spawn(|| {
+        let (_stream, handle) = match rodio::OutputStream::try_default() {
+            Ok(output) => output,
+            Err(_) => return,
+        };
+
+        let sink = match rodio::Sink::try_new(&handle) {
+            Ok(sink) => sink,
+            Err(_) => return,
+        };
+
+        if let Ok(source) = rodio::Decoder::new(Cursor::new(ALERT_SOUND)) {
+            sink.append(source);
+            sink.sleep_until_end();
+        }
+    });
+}
+
+// Seconds since the last user input, used by the idle-shutdown mode. Returns 0 when
+// the OS idle time cannot be determined.
+#[tauri::command]
+fn idle_seconds() -> u64 {
+    #[cfg(target_os = "windows")]
+    {
+        #[repr(C)]
+        struct LastInputInfo {
+            cb: u32,
+            dw_time: u32,
+        }
+
+        #[link(name = "user32")]
+        extern "system" {
+            fn GetLastInputInfo(plii: *mut LastInputInfo) -> i32;
+        }
+        #[link(name = "kernel32")]
+        extern "system" {
+            fn GetTickCount() -> u32;
+        }
+
+        unsafe {
+            let mut info = LastInputInfo {
+                cb: std::mem::size_of::<LastInputInfo>() as u32,
+                dw_time: 0,
+            };
+            if GetLastInputInfo(&mut info) != 0 {
+                return (GetTickCount().wrapping_sub(info.dw_time) / 1000) as u64;
+            }
+        }
+
+        0
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        // HIDIdleTime is reported in nanoseconds by the IOHIDSystem.
+        let output = Command::new("sh")
+            .arg("-c")
+            .arg("ioreg -c IOHIDSystem | awk '/HIDIdleTime/ {print $NF; exit}'")
+            .output();
+
+        output
+            .ok()
+            .and_then(|out| String::from_utf8(out.stdout).ok())
+            .and_then(|text| text.trim().parse::<u64>().ok())
+            .map(|nanos| nanos / 1_000_000_000)
+            .unwrap_or(0)
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        // Query the X11 screensaver extension directly; returns 0 when there is no
+        // reachable X display (e.g. a headless or Wayland-only session).
+        x11_idle_seconds().unwrap_or(0)
+    }
+}
+
+// Idle time via XScreenSaverQueryInfo from libXss. `None` when the display cannot be
+// opened or the query fails, so the caller can treat it as "unknown".
+#[cfg(target_os = "linux")]
+fn x11_idle_seconds() -> Option<u64> {
+    use std::ffi::c_void;
+    use std::os::raw::{c_char, c_int, c_ulong};
+    use std::ptr;
+
+    #[repr(C)]
+    struct XScreenSaverInfo {
+        window: c_ulong,
+        state: c_int,
+        kind: c_int,
+        til_or_since: c_ulong,
+        idle: c_ulong,
+        event_mask: c_ulong,
+    }
+
+    #[link(name = "X11")]
+    extern "C" {
+        fn XOpenDisplay(display_name: *const c_char) -> *mut c_void;
+        fn XCloseDisplay(display: *mut c_void) -> c_int;
+        fn XDefaultRootWindow(display: *mut c_void) -> c_ulong;
+        fn XFree(data: *mut c_void) -> c_int;
+    }
+    #[link(name = "Xss")]
+    extern "C" {
+        fn XScreenSaverAllocInfo() -> *mut XScreenSaverInfo;
+        fn XScreenSaverQueryInfo(
+            display: *mut c_void,
+            drawable: c_ulong,
+            info: *mut XScreenSaverInfo,
+        ) -> c_int;
+    }
+
+    unsafe {
+        let display = XOpenDisplay(ptr::null());
+        if display.is_null() {
+            return None;
+        }
+
+        let info = XScreenSaverAllocInfo();
+        if info.is_null() {
+            XCloseDisplay(display);
+            return None;
+        }
+
+        let root = XDefaultRootWindow(display);
+        let status = XScreenSaverQueryInfo(display, root, info);
+        let idle_millis = (*info).idle as u64;
+
+        XFree(info as *mut c_void);
+        XCloseDisplay(display);
+
+        if status != 0 {
+            Some(idle_millis / 1000)
+        } else {
+            None
+        }
     }
 }
 
 fn main() {
     tauri::Builder::default()
-        .invoke_handler(tauri::generate_handler![shutdown_pc])
+        .invoke_handler(tauri::generate_handler![
+            power_action,
+            play_alert,
+            load_settings,
+            save_settings,
+            idle_seconds
+        ])
         .setup(|app| {
             let splashscreen_window = app.get_window("splashscreen").unwrap();
             let main_window = app.get_window("main").unwrap();
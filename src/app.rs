@@ -1,44 +1,211 @@
 use chrono::{
     DateTime, Days, Duration, FixedOffset, Local, NaiveDateTime, NaiveTime, ParseResult, TimeZone,
 };
-use gloo_timers::callback::Timeout;
+use gloo_timers::callback::{Interval, Timeout};
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 use web_sys::HtmlInputElement;
-use yew::{html, Component, Context, Html, InputEvent, TargetCast};
+use yew::{html, Component, Context, Event, Html, InputEvent, TargetCast};
 
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "tauri"])]
-    fn invoke(cmd: &str);
+    fn invoke(cmd: &str, args: JsValue);
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "tauri"], js_name = invoke, catch)]
+    async fn invoke_with_result(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
 }
 
 const FORCE_SHUTDOWN_COUNTER: u8 = 3;
 
+/// Power action the scheduled timer and the "Now" button operate on. Serialized to
+/// the matching PascalCase string the `power_action` Tauri command deserializes.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum PowerAction {
+    Shutdown,
+    Restart,
+    Sleep,
+    Hibernate,
+    LogOff,
+}
+
+impl PowerAction {
+    const ALL: [PowerAction; 5] = [
+        PowerAction::Shutdown,
+        PowerAction::Restart,
+        PowerAction::Sleep,
+        PowerAction::Hibernate,
+        PowerAction::LogOff,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            PowerAction::Shutdown => "Shutdown",
+            PowerAction::Restart => "Restart",
+            PowerAction::Sleep => "Sleep",
+            PowerAction::Hibernate => "Hibernate",
+            PowerAction::LogOff => "Log Off",
+        }
+    }
+
+    fn from_label(label: &str) -> PowerAction {
+        PowerAction::ALL
+            .into_iter()
+            .find(|action| action.label() == label)
+            .unwrap_or(PowerAction::Shutdown)
+    }
+}
+
+#[derive(Serialize)]
+struct PowerArgs {
+    action: PowerAction,
+}
+
+/// Phase of a running pomodoro session. The state machine walks Work -> break and
+/// promotes every fourth break to a long one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PomodoroPhase {
+    Work,
+    ShortBreak,
+    LongBreak,
+}
+
+impl PomodoroPhase {
+    fn label(&self) -> &'static str {
+        match self {
+            PomodoroPhase::Work => "Work",
+            PomodoroPhase::ShortBreak => "Short Break",
+            PomodoroPhase::LongBreak => "Long Break",
+        }
+    }
+}
+
+fn invoke_power_action(action: PowerAction) {
+    let args = serde_wasm_bindgen::to_value(&PowerArgs { action }).unwrap();
+    invoke("power_action", args);
+}
+
+/// Persisted settings mirrored from the `Settings` struct on the Rust side.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Settings {
+    pub default_action: PowerAction,
+    pub warn_seconds: u32,
+    pub force_shutdown_count: u8,
+    pub presets: Vec<i64>,
+    #[serde(default)]
+    pub power_off_on_complete: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            default_action: PowerAction::Shutdown,
+            warn_seconds: 60,
+            force_shutdown_count: FORCE_SHUTDOWN_COUNTER,
+            presets: vec![10, 20, 30],
+            power_off_on_complete: false,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SaveArgs {
+    settings: Settings,
+}
+
 pub struct App {
     shutdown_time: Option<DateTime<FixedOffset>>,
+    deadline: Option<DateTime<FixedOffset>>,
     timeout_handle: Option<Timeout>,
+    interval_handle: Option<Interval>,
     force_shutdown_counter: u8,
     remain_second_for_shutdown: u32,
+    total_second_for_shutdown: u32,
+    paused_remaining: Option<Duration>,
+    selected_action: PowerAction,
+    work_minutes: i64,
+    short_break_minutes: i64,
+    long_break_minutes: i64,
+    cycles_per_long_break: u32,
+    completed_work_count: u32,
+    pomodoro_phase: Option<PomodoroPhase>,
+    power_off_on_complete: bool,
+    warn_seconds: u32,
+    warning_active: bool,
+    force_shutdown_count: u8,
+    presets: Vec<i64>,
+    new_preset_minutes: i64,
+    idle_threshold: Option<u32>,
+    idle_threshold_input: u32,
+    current_idle: u32,
     is_countdown_active: bool,
 }
 
 pub enum Msg {
     UpdateShutdownTime(String),
     SetShutdownTimer,
-    Shutdown(bool),
+    RunAction(bool),
+    SelectAction(PowerAction),
     PredefinedShutdownTime(i64),
+    Tick,
+    StartPomodoro,
+    PomodoroPhaseElapsed,
+    UpdateWorkLength(i64),
+    UpdateShortBreakLength(i64),
+    UpdateLongBreakLength(i64),
+    TogglePowerOffOnComplete,
+    UpdateWarnSeconds(u32),
+    SettingsLoaded(Settings),
+    UpdateNewPreset(i64),
+    AddPreset,
+    RemovePreset(usize),
+    UpdateIdleThreshold(u32),
+    EnableIdleShutdown(u32),
+    DisableIdleShutdown,
+    IdleTick(u64),
+    CancelShutdown,
+    PauseShutdown,
+    ResumeShutdown,
 }
 
 impl Component for App {
     type Message = Msg;
     type Properties = ();
 
-    fn create(_ctx: &Context<Self>) -> Self {
+    fn create(ctx: &Context<Self>) -> Self {
+        ctx.link().send_future(async {
+            let settings = match invoke_with_result("load_settings", JsValue::UNDEFINED).await {
+                Ok(value) => serde_wasm_bindgen::from_value(value).unwrap_or_default(),
+                Err(_) => Settings::default(),
+            };
+            Msg::SettingsLoaded(settings)
+        });
+
         Self {
             shutdown_time: Some(Local::now().fixed_offset()),
+            deadline: None,
             timeout_handle: None,
+            interval_handle: None,
             force_shutdown_counter: FORCE_SHUTDOWN_COUNTER,
             remain_second_for_shutdown: 0,
+            total_second_for_shutdown: 0,
+            paused_remaining: None,
+            selected_action: PowerAction::Shutdown,
+            work_minutes: 25,
+            short_break_minutes: 5,
+            long_break_minutes: 15,
+            cycles_per_long_break: 4,
+            completed_work_count: 0,
+            pomodoro_phase: None,
+            power_off_on_complete: false,
+            warn_seconds: 60,
+            warning_active: false,
+            force_shutdown_count: FORCE_SHUTDOWN_COUNTER,
+            presets: vec![10, 20, 30],
+            new_preset_minutes: 15,
+            idle_threshold: None,
+            idle_threshold_input: 600,
+            current_idle: 0,
             is_countdown_active: true,
         }
     }
@@ -56,6 +223,9 @@ impl Component for App {
                 if let Some(handle) = self.timeout_handle.take() {
                     handle.cancel();
                 }
+                if let Some(interval) = self.interval_handle.take() {
+                    interval.cancel();
+                }
                 if self.shutdown_time.is_some() {
                     let current_time = chrono::Local::now();
                     let mut new_time = current_time
@@ -69,27 +239,278 @@ impl Component for App {
                     let duration = new_time.signed_duration_since(chrono::Local::now());
 
                     if duration.num_seconds() > 0 {
+                        self.deadline = Some(new_time.fixed_offset());
+                        self.total_second_for_shutdown = duration.num_seconds() as u32;
+                        self.remain_second_for_shutdown = duration.num_seconds() as u32;
+                        self.warning_active = false;
+
                         let link = _ctx.link().clone();
                         let handle =
                             Timeout::new(duration.num_seconds() as u32 * 1000, move || {
-                                link.send_message(Msg::Shutdown(true));
+                                link.send_message(Msg::RunAction(true));
                             });
 
+                        let tick_link = _ctx.link().clone();
+                        let interval = Interval::new(1000, move || {
+                            tick_link.send_message(Msg::Tick);
+                        });
+                        self.interval_handle = Some(interval);
+
                         self.set_shutdown_time(Some(handle))
                     }
                 }
 
                 true
             }
-            Msg::Shutdown(is_from_system) => {
+            Msg::Tick => {
+                if let Some(deadline) = self.deadline {
+                    let remaining = deadline
+                        .signed_duration_since(Local::now())
+                        .num_seconds()
+                        .max(0);
+                    self.remain_second_for_shutdown = remaining as u32;
+
+                    if remaining > 0
+                        && remaining as u32 <= self.warn_seconds
+                        && !self.warning_active
+                        && self.pomodoro_phase.is_none()
+                    {
+                        self.warning_active = true;
+                        invoke("play_alert", JsValue::UNDEFINED);
+                    }
+
+                    if remaining == 0 {
+                        if let Some(interval) = self.interval_handle.take() {
+                            interval.cancel();
+                        }
+                    }
+                }
+
+                if self.idle_threshold.is_some() {
+                    _ctx.link().send_future(async {
+                        let seconds = match invoke_with_result("idle_seconds", JsValue::UNDEFINED)
+                            .await
+                        {
+                            Ok(value) => serde_wasm_bindgen::from_value(value).unwrap_or(0),
+                            Err(_) => 0,
+                        };
+                        Msg::IdleTick(seconds)
+                    });
+                }
+
+                true
+            }
+            Msg::UpdateIdleThreshold(seconds) => {
+                self.idle_threshold_input = seconds;
+
+                true
+            }
+            Msg::EnableIdleShutdown(threshold) => {
+                self.idle_threshold = Some(threshold);
+                self.current_idle = 0;
+
+                if let Some(interval) = self.interval_handle.take() {
+                    interval.cancel();
+                }
+                let tick_link = _ctx.link().clone();
+                let interval = Interval::new(1000, move || {
+                    tick_link.send_message(Msg::Tick);
+                });
+                self.interval_handle = Some(interval);
+
+                true
+            }
+            Msg::DisableIdleShutdown => {
+                self.idle_threshold = None;
+                if let Some(interval) = self.interval_handle.take() {
+                    interval.cancel();
+                }
+
+                true
+            }
+            Msg::IdleTick(seconds) => {
+                self.current_idle = seconds as u32;
+
+                if let Some(threshold) = self.idle_threshold {
+                    if self.current_idle >= threshold {
+                        invoke_power_action(self.selected_action);
+                        self.idle_threshold = None;
+                        if let Some(interval) = self.interval_handle.take() {
+                            interval.cancel();
+                        }
+                    }
+                }
+
+                true
+            }
+            Msg::UpdateWarnSeconds(seconds) => {
+                self.warn_seconds = seconds;
+                self.persist_settings();
+
+                true
+            }
+            Msg::SettingsLoaded(settings) => {
+                self.selected_action = settings.default_action;
+                self.warn_seconds = settings.warn_seconds;
+                self.force_shutdown_count = settings.force_shutdown_count;
+                self.force_shutdown_counter = settings.force_shutdown_count;
+                self.presets = settings.presets;
+                self.power_off_on_complete = settings.power_off_on_complete;
+
+                true
+            }
+            Msg::UpdateNewPreset(minutes) => {
+                self.new_preset_minutes = minutes;
+
+                true
+            }
+            Msg::AddPreset => {
+                if self.new_preset_minutes > 0 && !self.presets.contains(&self.new_preset_minutes) {
+                    self.presets.push(self.new_preset_minutes);
+                    self.persist_settings();
+                }
+
+                true
+            }
+            Msg::RemovePreset(index) => {
+                if index < self.presets.len() {
+                    self.presets.remove(index);
+                    self.persist_settings();
+                }
+
+                true
+            }
+            Msg::StartPomodoro => {
+                self.completed_work_count = 0;
+                self.arm_pomodoro_phase(_ctx, PomodoroPhase::Work);
+
+                true
+            }
+            Msg::PomodoroPhaseElapsed => {
+                // Completing the long break ends the full session and loops back to idle.
+                let session_complete = self.pomodoro_phase == Some(PomodoroPhase::LongBreak);
+
+                let next = match self.pomodoro_phase {
+                    Some(PomodoroPhase::Work) => {
+                        self.completed_work_count += 1;
+                        if self.completed_work_count % self.cycles_per_long_break == 0 {
+                            Some(PomodoroPhase::LongBreak)
+                        } else {
+                            Some(PomodoroPhase::ShortBreak)
+                        }
+                    }
+                    Some(PomodoroPhase::LongBreak) => None,
+                    Some(PomodoroPhase::ShortBreak) => Some(PomodoroPhase::Work),
+                    None => None,
+                };
+
+                match next {
+                    Some(phase) => self.arm_pomodoro_phase(_ctx, phase),
+                    None => {
+                        self.pomodoro_phase = None;
+                        self.is_countdown_active = false;
+                        if let Some(interval) = self.interval_handle.take() {
+                            interval.cancel();
+                        }
+
+                        // Only power off if the user explicitly opted in, and route it
+                        // through the normal warn-countdown/abort path rather than
+                        // firing the action silently.
+                        if session_complete && self.power_off_on_complete {
+                            self.arm_power_action_countdown(_ctx);
+                        }
+                    }
+                }
+
+                true
+            }
+            Msg::UpdateWorkLength(minutes) => {
+                self.work_minutes = minutes;
+
+                true
+            }
+            Msg::UpdateShortBreakLength(minutes) => {
+                self.short_break_minutes = minutes;
+
+                true
+            }
+            Msg::UpdateLongBreakLength(minutes) => {
+                self.long_break_minutes = minutes;
+
+                true
+            }
+            Msg::TogglePowerOffOnComplete => {
+                self.power_off_on_complete = !self.power_off_on_complete;
+                self.persist_settings();
+
+                true
+            }
+            Msg::CancelShutdown => {
+                self.reset();
+
+                true
+            }
+            Msg::PauseShutdown => {
+                if let Some(deadline) = self.deadline {
+                    let remaining = deadline.signed_duration_since(Local::now());
+                    self.paused_remaining = Some(remaining.max(Duration::zero()));
+
+                    if let Some(handle) = self.timeout_handle.take() {
+                        handle.cancel();
+                    }
+                    if let Some(interval) = self.interval_handle.take() {
+                        interval.cancel();
+                    }
+
+                    self.is_countdown_active = false;
+                }
+
+                true
+            }
+            Msg::ResumeShutdown => {
+                if let Some(remaining) = self.paused_remaining.take() {
+                    let new_deadline = Local::now() + remaining;
+                    self.deadline = Some(new_deadline.fixed_offset());
+                    self.remain_second_for_shutdown = remaining.num_seconds().max(0) as u32;
+
+                    // A paused pomodoro phase must resume into its phase timer, not a
+                    // power action, or a resumed focus session would power the machine off.
+                    let is_pomodoro = self.pomodoro_phase.is_some();
+                    let link = _ctx.link().clone();
+                    let handle = Timeout::new(remaining.num_seconds().max(0) as u32 * 1000, move || {
+                        if is_pomodoro {
+                            link.send_message(Msg::PomodoroPhaseElapsed);
+                        } else {
+                            link.send_message(Msg::RunAction(true));
+                        }
+                    });
+
+                    let tick_link = _ctx.link().clone();
+                    let interval = Interval::new(1000, move || {
+                        tick_link.send_message(Msg::Tick);
+                    });
+                    self.interval_handle = Some(interval);
+
+                    self.set_shutdown_time(Some(handle));
+                }
+
+                true
+            }
+            Msg::RunAction(is_from_system) => {
                 if self.force_shutdown_counter == 0 || is_from_system {
-                    invoke("shutdown_pc");
+                    invoke_power_action(self.selected_action);
                 } else {
                     self.force_shutdown_counter -= 1;
                 }
 
                 true
             }
+            Msg::SelectAction(action) => {
+                self.selected_action = action;
+                self.persist_settings();
+
+                true
+            }
             Msg::PredefinedShutdownTime(predefined_time) => {
                 let postpone_time = Local::now() + Duration::minutes(predefined_time);
                 self.shutdown_time = Some(postpone_time.fixed_offset());
@@ -104,14 +525,23 @@ impl Component for App {
         <div class="flex min-h-screen flex-col items-center justify-center">
             <img src="public/samurai-logo.png" alt="Logo" class="mx-auto mb-4 h-32 w-32 logo" />
 
-            // <div class="mt-2 w-full max-w-sm px-14">
-            //     <div class="w-full bg-gray-200 rounded-full h-2.5 dark:bg-gray-700">
-            //         <div class="bg-red-600 h-2.5 rounded-full dark:bg-red-500" style="width: 0%"></div>
-            //     </div>
-            //     <div class="flex justify-center mt-1">
-            //         <span class="text-sm font-medium text-blue-700 dark:text-white">{"Remaining Minute: -"}</span>
-            //     </div>
-            // </div>
+            if self.warning_active {
+                <div class="flex items-center justify-between w-full max-w-sm px-14 mb-2">
+                    <div class="flex w-full items-center justify-between rounded-md bg-red-600 px-4 py-2 text-white">
+                        <span class="text-sm font-medium">{self.selected_action.label()}{" in "}{self.remaining_label()}</span>
+                        <button onclick={ctx.link().callback(|_| Msg::CancelShutdown)} class="ml-3 rounded-md bg-neutral-900 px-3 py-1 text-white">{"Abort"}</button>
+                    </div>
+                </div>
+            }
+
+            <div class="mt-2 w-full max-w-sm px-14">
+                <div class="w-full bg-gray-200 rounded-full h-2.5 dark:bg-gray-700">
+                    <div class="bg-red-600 h-2.5 rounded-full dark:bg-red-500" style={format!("width: {}%", self.progress_percent())}></div>
+                </div>
+                <div class="flex justify-center mt-1">
+                    <span class="text-sm font-medium text-blue-700 dark:text-white">{"Remaining: "}{self.remaining_label()}</span>
+                </div>
+            </div>
 
             <div class="flex items-center mt-4">
                 <input type="time" placeholder="hrs:mins" class="mr-2 w-full rounded-md bg-neutral-900 px-6 py-2 text-white" min="00:00" max="23:59" value={
@@ -128,16 +558,117 @@ impl Component for App {
                 <button onclick={ctx.link().callback(|_| Msg::SetShutdownTimer)} class="rounded-md bg-neutral-900 px-4 py-2 text-white">{"Set"}</button>
             </div>
 
+            <div class="flex items-center mt-3 flex-wrap justify-center">
+                {
+                    self.presets.iter().enumerate().map(|(index, minutes)| {
+                        let minutes = *minutes;
+                        html! {
+                            <span class="flex items-center mr-2 mb-1 rounded-lg bg-green-600 px-2 py-1 text-xs text-white">
+                                <span onclick={ctx.link().callback(move |_| Msg::PredefinedShutdownTime(minutes))}>{minutes}{" Min"}</span>
+                                <button onclick={ctx.link().callback(move |_| Msg::RemovePreset(index))} class="ml-1 text-white">{"×"}</button>
+                            </span>
+                        }
+                    }).collect::<Html>()
+                }
+            </div>
+
+            <div class="flex items-center mt-2">
+                <input type="number" min="1" class="mr-2 w-20 rounded-md bg-neutral-900 px-2 py-1 text-white" value={self.new_preset_minutes.to_string()}
+                    oninput={ctx.link().callback(|e: InputEvent| {
+                        let input: HtmlInputElement = e.target_unchecked_into();
+                        Msg::UpdateNewPreset(input.value().trim().parse().unwrap_or(0))
+                    })} />
+                <button onclick={ctx.link().callback(|_| Msg::AddPreset)} class="rounded-md bg-neutral-900 px-3 py-1 text-white">{"Add Preset"}</button>
+            </div>
+
+            <div class="flex items-center mt-3">
+                <select class="rounded-md bg-neutral-900 px-4 py-2 text-white"
+                    onchange={ctx.link().callback(|e: Event| {
+                        let select: HtmlInputElement = e.target_unchecked_into();
+                        Msg::SelectAction(PowerAction::from_label(&select.value()))
+                    })}>
+                    {
+                        PowerAction::ALL.iter().map(|action| html! {
+                            <option value={action.label()} selected={*action == self.selected_action}>{action.label()}</option>
+                        }).collect::<Html>()
+                    }
+                </select>
+            </div>
+
             <div class="flex items-center mt-3">
-                <span onclick={ctx.link().callback(|_| Msg::PredefinedShutdownTime(10))} class="left-0 top-0 mr-2 rounded-lg bg-green-500 px-2 py-1 text-xs text-white">{"10 Min"}</span>
-                <span onclick={ctx.link().callback(|_| Msg::PredefinedShutdownTime(20))} class="left-0 top-0 mr-2 rounded-lg bg-green-600 px-2 py-1 text-xs text-white">{"20 Min"}</span>
-                <span onclick={ctx.link().callback(|_| Msg::PredefinedShutdownTime(30))} class="left-0 top-0 rounded-lg bg-green-700 px-2 py-1 text-xs text-white">{"30 Min"}</span>
+                <span class="mr-2 text-sm font-medium text-blue-700 dark:text-white">{"Warn (s)"}</span>
+                <input type="number" min="0" class="w-20 rounded-md bg-neutral-900 px-2 py-1 text-white" value={self.warn_seconds.to_string()}
+                    oninput={ctx.link().callback(|e: InputEvent| {
+                        let input: HtmlInputElement = e.target_unchecked_into();
+                        Msg::UpdateWarnSeconds(input.value().trim().parse().unwrap_or(60))
+                    })} />
             </div>
 
             <div class="mt-2">
-                <button onclick={ctx.link().callback(|_| Msg::Shutdown(false))} class="rounded-md bg-orange-600 flex px-4 py-2 text-white mt-2">{"Shutdown Now"} {" ("}{self.force_shutdown_counter}{")"}</button>
+                <button onclick={ctx.link().callback(|_| Msg::RunAction(false))} class="rounded-md bg-orange-600 flex px-4 py-2 text-white mt-2">{self.selected_action.label()}{" Now"} {" ("}{self.force_shutdown_counter}{")"}</button>
+            </div>
+
+            <div class="flex flex-col items-center mt-4 w-full max-w-sm px-14">
+                <span class="text-sm font-medium text-blue-700 dark:text-white mb-1">{"Pomodoro"}</span>
+                <div class="flex items-center">
+                    <input type="number" min="1" title="Work (min)" class="mr-2 w-16 rounded-md bg-neutral-900 px-2 py-1 text-white" value={self.work_minutes.to_string()}
+                        oninput={ctx.link().callback(|e: InputEvent| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            Msg::UpdateWorkLength(input.value().trim().parse().unwrap_or(25))
+                        })} />
+                    <input type="number" min="1" title="Short break (min)" class="mr-2 w-16 rounded-md bg-neutral-900 px-2 py-1 text-white" value={self.short_break_minutes.to_string()}
+                        oninput={ctx.link().callback(|e: InputEvent| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            Msg::UpdateShortBreakLength(input.value().trim().parse().unwrap_or(5))
+                        })} />
+                    <input type="number" min="1" title="Long break (min)" class="mr-2 w-16 rounded-md bg-neutral-900 px-2 py-1 text-white" value={self.long_break_minutes.to_string()}
+                        oninput={ctx.link().callback(|e: InputEvent| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            Msg::UpdateLongBreakLength(input.value().trim().parse().unwrap_or(15))
+                        })} />
+                    <button onclick={ctx.link().callback(|_| Msg::StartPomodoro)} class="rounded-md bg-neutral-900 px-4 py-1 text-white">{"Start"}</button>
+                </div>
+                <label class="flex items-center mt-1 text-xs font-medium text-blue-700 dark:text-white">
+                    <input type="checkbox" class="mr-1" checked={self.power_off_on_complete}
+                        onchange={ctx.link().callback(|_| Msg::TogglePowerOffOnComplete)} />
+                    {"Power off when session completes"}
+                </label>
+                if let Some(phase) = self.pomodoro_phase {
+                    <span class="text-sm font-medium text-blue-700 dark:text-white mt-1">{phase.label()}{": "}{self.remaining_label()}</span>
+                }
             </div>
 
+            <div class="flex flex-col items-center mt-4 w-full max-w-sm px-14">
+                <span class="text-sm font-medium text-blue-700 dark:text-white mb-1">{"Idle Shutdown"}</span>
+                if let Some(threshold) = self.idle_threshold {
+                    <span class="text-sm font-medium text-blue-700 dark:text-white">{format!("Idle {}s / {}s", self.current_idle, threshold)}</span>
+                    <button onclick={ctx.link().callback(|_| Msg::DisableIdleShutdown)} class="mt-1 rounded-md bg-neutral-900 px-4 py-1 text-white">{"Disable"}</button>
+                } else {
+                    <div class="flex items-center">
+                        <input type="number" min="1" title="Idle threshold (s)" class="mr-2 w-20 rounded-md bg-neutral-900 px-2 py-1 text-white" value={self.idle_threshold_input.to_string()}
+                            oninput={ctx.link().callback(|e: InputEvent| {
+                                let input: HtmlInputElement = e.target_unchecked_into();
+                                Msg::UpdateIdleThreshold(input.value().trim().parse().unwrap_or(600))
+                            })} />
+                        <button onclick={
+                            let threshold = self.idle_threshold_input;
+                            ctx.link().callback(move |_| Msg::EnableIdleShutdown(threshold))
+                        } class="rounded-md bg-neutral-900 px-4 py-1 text-white">{"Enable"}</button>
+                    </div>
+                }
+            </div>
+
+            if self.is_countdown_active {
+                <div class="flex items-center mt-3">
+                    <button onclick={ctx.link().callback(|_| Msg::PauseShutdown)} class="mr-2 rounded-md bg-yellow-600 px-4 py-2 text-white">{"Pause"}</button>
+                    <button onclick={ctx.link().callback(|_| Msg::CancelShutdown)} class="rounded-md bg-neutral-900 px-4 py-2 text-white">{"Cancel"}</button>
+                </div>
+            } else if self.paused_remaining.is_some() {
+                <div class="flex items-center mt-3">
+                    <button onclick={ctx.link().callback(|_| Msg::ResumeShutdown)} class="rounded-md bg-green-600 px-4 py-2 text-white">{"Resume"}</button>
+                </div>
+            }
+
         </div>
         }
     }
@@ -162,13 +693,131 @@ impl App {
         ParseResult::Ok(fixed_offset_datetime)
     }
 
+    // Snapshot the current settings and write them through the `save_settings`
+    // command so presets and preferences survive a restart.
+    fn persist_settings(&self) {
+        let settings = Settings {
+            default_action: self.selected_action,
+            warn_seconds: self.warn_seconds,
+            force_shutdown_count: self.force_shutdown_count,
+            presets: self.presets.clone(),
+            power_off_on_complete: self.power_off_on_complete,
+        };
+
+        if let Ok(args) = serde_wasm_bindgen::to_value(&SaveArgs { settings }) {
+            invoke("save_settings", args);
+        }
+    }
+
+    // Arm a scheduled power action a short lead-time away (the warn window), so the
+    // end-of-session shutdown surfaces the same warning banner and Abort button as a
+    // normally scheduled action instead of firing silently.
+    fn arm_power_action_countdown(&mut self, ctx: &Context<Self>) {
+        if let Some(handle) = self.timeout_handle.take() {
+            handle.cancel();
+        }
+        if let Some(interval) = self.interval_handle.take() {
+            interval.cancel();
+        }
+
+        let lead = self.warn_seconds.max(1);
+        let duration = Duration::seconds(lead as i64);
+        self.deadline = Some((Local::now() + duration).fixed_offset());
+        self.total_second_for_shutdown = lead;
+        self.remain_second_for_shutdown = lead;
+        self.warning_active = false;
+
+        let link = ctx.link().clone();
+        let handle = Timeout::new(lead * 1000, move || {
+            link.send_message(Msg::RunAction(true));
+        });
+
+        let tick_link = ctx.link().clone();
+        let interval = Interval::new(1000, move || {
+            tick_link.send_message(Msg::Tick);
+        });
+        self.interval_handle = Some(interval);
+
+        self.set_shutdown_time(Some(handle));
+    }
+
+    fn phase_minutes(&self, phase: PomodoroPhase) -> i64 {
+        match phase {
+            PomodoroPhase::Work => self.work_minutes,
+            PomodoroPhase::ShortBreak => self.short_break_minutes,
+            PomodoroPhase::LongBreak => self.long_break_minutes,
+        }
+    }
+
+    // Enter `phase`: seed the shared countdown state and arm a Timeout that fires
+    // `PomodoroPhaseElapsed`, reusing the per-second tick for the visible countdown.
+    fn arm_pomodoro_phase(&mut self, ctx: &Context<Self>, phase: PomodoroPhase) {
+        if let Some(handle) = self.timeout_handle.take() {
+            handle.cancel();
+        }
+        if let Some(interval) = self.interval_handle.take() {
+            interval.cancel();
+        }
+
+        let duration = Duration::minutes(self.phase_minutes(phase));
+        self.pomodoro_phase = Some(phase);
+        self.deadline = Some((Local::now() + duration).fixed_offset());
+        self.total_second_for_shutdown = duration.num_seconds().max(0) as u32;
+        self.remain_second_for_shutdown = duration.num_seconds().max(0) as u32;
+        self.warning_active = false;
+
+        let link = ctx.link().clone();
+        let handle = Timeout::new(duration.num_seconds().max(0) as u32 * 1000, move || {
+            link.send_message(Msg::PomodoroPhaseElapsed);
+        });
+
+        let tick_link = ctx.link().clone();
+        let interval = Interval::new(1000, move || {
+            tick_link.send_message(Msg::Tick);
+        });
+        self.interval_handle = Some(interval);
+
+        self.set_shutdown_time(Some(handle));
+    }
+
+    fn progress_percent(&self) -> u32 {
+        if self.total_second_for_shutdown == 0 {
+            return 0;
+        }
+
+        self.remain_second_for_shutdown * 100 / self.total_second_for_shutdown
+    }
+
+    fn remaining_label(&self) -> String {
+        let total = self.remain_second_for_shutdown;
+        let hours = total / 3600;
+        let minutes = (total % 3600) / 60;
+        let seconds = total % 60;
+
+        format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+    }
+
     fn set_shutdown_time(&mut self, timeout: Option<Timeout>) {
         self.is_countdown_active = true;
         self.timeout_handle = timeout;
     }
 
+    // Tear down any pending countdown and return the component to its idle state.
     fn reset(&mut self) {
+        if let Some(handle) = self.timeout_handle.take() {
+            handle.cancel();
+        }
+        if let Some(interval) = self.interval_handle.take() {
+            interval.cancel();
+        }
+
         self.is_countdown_active = false;
-        self.timeout_handle = None;
+        self.force_shutdown_counter = self.force_shutdown_count;
+        self.paused_remaining = None;
+        self.remain_second_for_shutdown = 0;
+        self.total_second_for_shutdown = 0;
+        self.deadline = None;
+        self.pomodoro_phase = None;
+        self.warning_active = false;
     }
 }